@@ -23,19 +23,117 @@
   let response = channel.req(123).unwrap();
   let reply = response.recv().unwrap();
   assert_eq!(reply, "world".to_string());
+  ```
+
+  # Features
+
+  The `crossbeam` feature swaps the internal transport for
+  `crossbeam-channel`, which can be faster under heavy contention. It
+  doesn't change the public API.
 */
 
-use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+#[cfg(not(feature = "crossbeam"))]
+use std::thread;
 use std::time::Duration;
 
-type Sender<T, R> = mpsc::Sender<Request<T, R>>;
-type InternalReceiver<T, R> = mpsc::Receiver<Request<T, R>>;
-type Receiver<R> = mpsc::Receiver<R>;
+/// The channel transport underlying `Server`/`Channel`. Behind the
+/// `crossbeam` feature this is backed by `crossbeam-channel` instead of
+/// `std::sync::mpsc`, without changing any public API.
+#[cfg(not(feature = "crossbeam"))]
+mod backend {
+    use std::sync::mpsc;
+
+    pub use std::sync::mpsc::{RecvError, RecvTimeoutError, SendError, TryRecvError, TrySendError};
+
+    pub type Sender<T> = mpsc::Sender<T>;
+    pub type SyncSender<T> = mpsc::SyncSender<T>;
+    pub type Receiver<T> = mpsc::Receiver<T>;
+
+    pub fn channel<T>() -> (Sender<T>, Receiver<T>) {
+        mpsc::channel()
+    }
+
+    pub fn sync_channel<T>(cap: usize) -> (SyncSender<T>, Receiver<T>) {
+        mpsc::sync_channel(cap)
+    }
+}
+
+#[cfg(feature = "crossbeam")]
+mod backend {
+    pub use crossbeam_channel::{RecvError, RecvTimeoutError, SendError, TryRecvError, TrySendError};
+    pub use crossbeam_channel::{Receiver, Sender};
+
+    // crossbeam-channel has no separate type for a bounded sender; a
+    // `Sender` supports both `send`/`try_send` regardless of capacity.
+    pub type SyncSender<T> = Sender<T>;
+
+    pub fn channel<T>() -> (Sender<T>, Receiver<T>) {
+        crossbeam_channel::unbounded()
+    }
+
+    pub fn sync_channel<T>(cap: usize) -> (SyncSender<T>, Receiver<T>) {
+        crossbeam_channel::bounded(cap)
+    }
+}
+
+/// The sending half of the internal request queue, either unbounded or
+/// bounded to a fixed capacity.
+#[derive(Debug)]
+enum Sender<T, R> {
+    Unbounded(backend::Sender<Request<T, R>>),
+    Bounded(backend::SyncSender<Request<T, R>>),
+}
 
-pub type SendError<R, T> = mpsc::SendError<Request<R, T>>;
-pub type RecvError = mpsc::RecvError;
-pub type TryRecvError = mpsc::TryRecvError;
-pub type RecvTimeoutError = mpsc::RecvTimeoutError;
+// `backend::Sender`/`SyncSender` are `Clone` regardless of `T, R`, but a
+// derived impl would add spurious `T: Clone, R: Clone` bounds, breaking
+// `Server::pop`/`Channel::clone` for non-`Clone` payloads and replies.
+impl<T, R> Clone for Sender<T, R> {
+    fn clone(&self) -> Self {
+        match self {
+            Sender::Unbounded(tx) => Sender::Unbounded(tx.clone()),
+            Sender::Bounded(tx) => Sender::Bounded(tx.clone()),
+        }
+    }
+}
+
+impl<T, R> Sender<T, R> {
+    fn send(&self, request: Request<T, R>) -> Result<(), backend::SendError<Request<T, R>>> {
+        match self {
+            Sender::Unbounded(tx) => tx.send(request),
+            Sender::Bounded(tx) => tx.send(request),
+        }
+    }
+
+    #[cfg(not(feature = "crossbeam"))]
+    fn try_send(&self, request: Request<T, R>) -> Result<(), backend::TrySendError<Request<T, R>>> {
+        match self {
+            Sender::Unbounded(tx) => tx.send(request).map_err(|backend::SendError(request)| {
+                backend::TrySendError::Disconnected(request)
+            }),
+            Sender::Bounded(tx) => tx.try_send(request),
+        }
+    }
+
+    // crossbeam-channel's `Sender::try_send` already works for both
+    // unbounded and bounded channels.
+    #[cfg(feature = "crossbeam")]
+    fn try_send(&self, request: Request<T, R>) -> Result<(), backend::TrySendError<Request<T, R>>> {
+        match self {
+            Sender::Unbounded(tx) => tx.try_send(request),
+            Sender::Bounded(tx) => tx.try_send(request),
+        }
+    }
+}
+
+type InternalReceiver<T, R> = backend::Receiver<Request<T, R>>;
+type Receiver<R> = backend::Receiver<R>;
+
+pub type SendError<R, T> = backend::SendError<Request<R, T>>;
+pub type RecvError = backend::RecvError;
+pub type TryRecvError = backend::TryRecvError;
+pub type RecvTimeoutError = backend::RecvTimeoutError;
+pub type TrySendError<T> = backend::TrySendError<T>;
 
 /// The server that receives requests and creates channels
 #[derive(Debug)]
@@ -54,9 +152,32 @@ impl<T, R> Server<T, R> {
     ///
     /// let server: mrsc::Server<u32, String> = mrsc::Server::new();
     pub fn new() -> Server<T, R> {
-        let (tx, rx) = mpsc::channel();
+        let (tx, rx) = backend::channel();
         Server {
-            tx,
+            tx: Sender::Unbounded(tx),
+            rx,
+        }
+    }
+
+    /// Create a new server backed by a bounded queue.
+    ///
+    /// Unlike `new()`, which queues an unlimited number of requests, this
+    /// caps the number of outstanding requests at `cap`, giving producers
+    /// flow control: once the queue is full, `Channel::req` blocks and
+    /// `Channel::try_req` returns `TrySendError::Full`. A `cap` of `0`
+    /// creates a rendezvous channel, where `req` blocks until the server
+    /// actually receives the request.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mrsc;
+    ///
+    /// let server: mrsc::Server<u32, String> = mrsc::Server::bounded(8);
+    pub fn bounded(cap: usize) -> Server<T, R> {
+        let (tx, rx) = backend::sync_channel(cap);
+        Server {
+            tx: Sender::Bounded(tx),
             rx,
         }
     }
@@ -104,17 +225,158 @@ impl<T, R> Server<T, R> {
     pub fn recv_timeout(&self, timeout: Duration) -> Result<Request<T, R>, RecvTimeoutError> {
         self.rx.recv_timeout(timeout)
     }
+
+    /// Returns an iterator that yields requests as they arrive, blocking
+    /// between each one.
+    ///
+    /// Note that `Server` keeps its own sender alive for `pop()`, so this
+    /// will not end just because every `Channel` has been dropped; pair it
+    /// with `.take(n)` or another external condition to stop iterating, or
+    /// use `into_iter()` to consume the server and get real termination.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use mrsc;
+    ///
+    /// let server: mrsc::Server<u32, String> = mrsc::Server::new();
+    ///
+    /// for req in server.iter().take(1) {
+    ///     req.reply("hello world".to_string()).unwrap();
+    /// }
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = Request<T, R>> + '_ {
+        self.rx.iter()
+    }
+
+    /// Returns an iterator that drains the requests currently queued,
+    /// without blocking.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mrsc;
+    ///
+    /// let server: mrsc::Server<u32, String> = mrsc::Server::new();
+    ///
+    /// for req in server.try_iter() {
+    ///     req.reply("hello world".to_string()).unwrap();
+    /// }
+    /// ```
+    pub fn try_iter(&self) -> impl Iterator<Item = Request<T, R>> + '_ {
+        self.rx.try_iter()
+    }
+
+    /// Turns this server into a `SharedServer`, allowing a pool of worker
+    /// threads to pull requests from the same incoming queue.
+    ///
+    /// Each request is still delivered to exactly one worker, and replies
+    /// flow back over the per-request channel as usual.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mrsc;
+    ///
+    /// let server: mrsc::Server<u32, String> = mrsc::Server::new();
+    /// let server = server.shared();
+    /// let worker = server.clone();
+    pub fn shared(self) -> SharedServer<T, R> {
+        SharedServer {
+            rx: Arc::new(Mutex::new(self.rx)),
+        }
+    }
+}
+
+/// Consumes the server, yielding requests as they arrive, blocking between
+/// each one.
+///
+/// Unlike `iter()`, this drops the server's own sender, so the iterator
+/// actually ends once every `Channel` has been dropped.
+///
+/// # Examples
+///
+/// ```no_run
+/// use mrsc;
+///
+/// let server: mrsc::Server<u32, String> = mrsc::Server::new();
+///
+/// for req in server {
+///     req.reply("hello world".to_string()).unwrap();
+/// }
+/// ```
+impl<T, R> IntoIterator for Server<T, R> {
+    type Item = Request<T, R>;
+    type IntoIter = <InternalReceiver<T, R> as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.rx.into_iter()
+    }
+}
+
+/// A handle to a pool of server threads sharing one incoming request queue.
+///
+/// Created with `Server::shared`. Can be cheaply cloned and handed to
+/// multiple worker threads; each request is delivered to exactly one clone.
+#[derive(Debug)]
+pub struct SharedServer<T, R> {
+    rx: Arc<Mutex<InternalReceiver<T, R>>>,
+}
+
+// A derived impl would add spurious `T: Clone, R: Clone` bounds; cloning
+// only shares the `Arc`.
+impl<T, R> Clone for SharedServer<T, R> {
+    fn clone(&self) -> Self {
+        SharedServer {
+            rx: Arc::clone(&self.rx),
+        }
+    }
+}
+
+impl<T, R> SharedServer<T, R> {
+    /// Receive a request from a worker thread.
+    pub fn recv(&self) -> Result<Request<T, R>, RecvError> {
+        self.rx.lock().unwrap().recv()
+    }
+
+    pub fn try_recv(&self) -> Result<Request<T, R>, TryRecvError> {
+        self.rx.lock().unwrap().try_recv()
+    }
+
+    pub fn recv_timeout(&self, timeout: Duration) -> Result<Request<T, R>, RecvTimeoutError> {
+        self.rx.lock().unwrap().recv_timeout(timeout)
+    }
+}
+
+impl<T, R> Default for Server<T, R> {
+    fn default() -> Self {
+        Server::new()
+    }
 }
 
 /// A channel to the server that can be used to send requests
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 pub struct Channel<T, R> {
     tx: Sender<T, R>,
 }
 
+// `Sender` is `Clone` regardless of `T, R` (see its own hand-written impl
+// above), but a derived impl here would still add spurious `T: Clone, R:
+// Clone` bounds on `Channel` itself.
+impl<T, R> Clone for Channel<T, R> {
+    fn clone(&self) -> Self {
+        Channel {
+            tx: self.tx.clone(),
+        }
+    }
+}
+
 impl<T, R> Channel<T, R> {
     /// Sends a new request to the server.
     ///
+    /// If the server was created with `Server::bounded`, this blocks until
+    /// there is room in the queue.
+    ///
     /// # Examples
     ///
     /// ```
@@ -125,14 +387,52 @@ impl<T, R> Channel<T, R> {
     /// let channel = server.pop();
     /// channel.req(123).unwrap();
     pub fn req(&self, payload: T) -> Result<Response<R>, SendError<T, R>> {
-        let (tx, rx) = mpsc::channel();
+        let (tx, rx) = backend::channel();
+        let (cancel_tx, cancel) = backend::channel();
         self.tx.send(Request {
             tx,
+            cancel,
+            payload
+        })?;
+
+        Ok(Response {
+            rx,
+            _cancel: cancel_tx,
+        })
+    }
+
+    /// Sends a new request to the server without blocking.
+    ///
+    /// If the server's queue is full (only possible for a server created
+    /// with `Server::bounded`), this returns `TrySendError::Full` instead
+    /// of waiting for room to free up.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mrsc;
+    ///
+    /// let server: mrsc::Server<u32, String> = mrsc::Server::bounded(1);
+    ///
+    /// let channel = server.pop();
+    /// channel.try_req(123).unwrap();
+    pub fn try_req(&self, payload: T) -> Result<Response<R>, TrySendError<T>> {
+        let (tx, rx) = backend::channel();
+        let (cancel_tx, cancel) = backend::channel();
+        self.tx.try_send(Request {
+            tx,
+            cancel,
             payload
+        }).map_err(|err| match err {
+            backend::TrySendError::Full(request) => backend::TrySendError::Full(request.payload),
+            backend::TrySendError::Disconnected(request) => {
+                backend::TrySendError::Disconnected(request.payload)
+            }
         })?;
 
         Ok(Response {
-            rx: rx
+            rx,
+            _cancel: cancel_tx,
         })
     }
 }
@@ -140,7 +440,8 @@ impl<T, R> Channel<T, R> {
 /// The request as seen by the server thread
 #[derive(Debug)]
 pub struct Request<T, R> {
-    tx: mpsc::Sender<R>,
+    tx: backend::Sender<R>,
+    cancel: backend::Receiver<()>,
     payload: T,
 }
 
@@ -154,6 +455,7 @@ impl<T, R> Request<T, R> {
     pub fn take(self) -> (EmptyRequest<R>, T) {
         (EmptyRequest {
             tx: self.tx,
+            cancel: self.cancel,
         }, self.payload)
     }
 
@@ -173,15 +475,32 @@ impl<T, R> Request<T, R> {
     /// // answer request
     /// let req = server.recv().unwrap();
     /// req.reply("hello world".to_string()).unwrap();
-    pub fn reply(self, response: R) -> Result<(), mpsc::SendError<R>> {
+    pub fn reply(self, response: R) -> Result<(), backend::SendError<R>> {
         self.tx.send(response)
     }
+
+    /// Returns `true` if the requester has already given up on this
+    /// request, i.e. its `Response` has been dropped without receiving a
+    /// reply.
+    pub fn is_cancelled(&self) -> bool {
+        matches!(self.cancel.try_recv(), Err(backend::TryRecvError::Disconnected))
+    }
+
+    /// Blocks until the requester gives up on this request, i.e. its
+    /// `Response` is dropped without receiving a reply.
+    ///
+    /// Useful for long-running handlers that want to abort early instead
+    /// of doing expensive work for a reply nobody is waiting for anymore.
+    pub fn cancelled(&self) {
+        let _ = self.cancel.recv();
+    }
 }
 
 /// A request without payload
 #[derive(Debug)]
 pub struct EmptyRequest<R> {
-    tx: mpsc::Sender<R>,
+    tx: backend::Sender<R>,
+    cancel: backend::Receiver<()>,
 }
 
 impl<R> EmptyRequest<R> {
@@ -202,15 +521,31 @@ impl<R> EmptyRequest<R> {
     /// let req = server.recv().unwrap();
     /// let (req, payload) = req.take();
     /// req.reply("hello world".to_string()).unwrap();
-    pub fn reply(self, response: R) -> Result<(), mpsc::SendError<R>> {
+    pub fn reply(self, response: R) -> Result<(), backend::SendError<R>> {
         self.tx.send(response)
     }
+
+    /// Returns `true` if the requester has already given up on this
+    /// request, i.e. its `Response` has been dropped without receiving a
+    /// reply.
+    pub fn is_cancelled(&self) -> bool {
+        matches!(self.cancel.try_recv(), Err(backend::TryRecvError::Disconnected))
+    }
+
+    /// Blocks until the requester gives up on this request, i.e. its
+    /// `Response` is dropped without receiving a reply.
+    pub fn cancelled(&self) {
+        let _ = self.cancel.recv();
+    }
 }
 
 /// The response returned to an request
 #[derive(Debug)]
 pub struct Response<R> {
     rx: Receiver<R>,
+    /// Kept alive only so dropping the `Response` is observable through
+    /// `Request::is_cancelled`/`cancelled` on the other end.
+    _cancel: backend::Sender<()>,
 }
 
 impl<R> Response<R> {
@@ -245,12 +580,63 @@ impl<R> Response<R> {
     pub fn recv_timeout(&self, timeout: Duration) -> Result<R, RecvTimeoutError> {
         self.rx.recv_timeout(timeout)
     }
+
+    /// Blocks until any one of the given responses is ready, then returns
+    /// its index and the received value. The other responses are left
+    /// intact and can be selected on again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mrsc;
+    ///
+    /// let server: mrsc::Server<u32, String> = mrsc::Server::new();
+    /// let channel = server.pop();
+    ///
+    /// let a = channel.req(1).unwrap();
+    /// let b = channel.req(2).unwrap();
+    ///
+    /// // only reply to the first request, so `b` isn't ready yet
+    /// server.recv().unwrap().reply("first".to_string()).unwrap();
+    ///
+    /// let (i, value) = mrsc::Response::select(&[a, b]);
+    /// assert_eq!(i, 0);
+    /// assert_eq!(value.unwrap(), "first".to_string());
+    #[cfg(feature = "crossbeam")]
+    pub fn select(responses: &[Response<R>]) -> (usize, Result<R, RecvError>) {
+        let mut select = crossbeam_channel::Select::new();
+        for response in responses {
+            select.recv(&response.rx);
+        }
+
+        let oper = select.select();
+        let index = oper.index();
+        let result = oper.recv(&responses[index].rx);
+        (index, result)
+    }
+
+    /// `std::sync::mpsc` has no primitive to wait on several receivers at
+    /// once, so without the `crossbeam` feature this falls back to
+    /// polling each response in turn.
+    #[cfg(not(feature = "crossbeam"))]
+    pub fn select(responses: &[Response<R>]) -> (usize, Result<R, RecvError>) {
+        loop {
+            for (i, response) in responses.iter().enumerate() {
+                match response.try_recv() {
+                    Ok(value) => return (i, Ok(value)),
+                    Err(TryRecvError::Disconnected) => return (i, Err(backend::RecvError)),
+                    Err(TryRecvError::Empty) => continue,
+                }
+            }
+            thread::sleep(Duration::from_millis(1));
+        }
+    }
 }
 
 
 #[cfg(test)]
 mod tests {
-    use super::Server;
+    use super::{Response, Server};
     use std::thread;
     use std::time::Duration;
 
@@ -307,12 +693,11 @@ mod tests {
         let channel = server.pop();
 
         thread::spawn(move || {
-            for i in &[1] {
-                let req = server.recv().unwrap();
-                let (req, payload) = req.take();
-                assert_eq!(&payload, i);
-                req.reply(format!("success: {}", i)).unwrap();
-            }
+            let i = 1;
+            let req = server.recv().unwrap();
+            let (req, payload) = req.take();
+            assert_eq!(payload, i);
+            req.reply(format!("success: {}", i)).unwrap();
         });
 
         let response = channel.req(1).unwrap();
@@ -337,6 +722,130 @@ mod tests {
         assert_eq!(result, 3);
     }
 
+    #[test]
+    fn bounded_try_req_full() {
+        let server: Server<u32, u32> = Server::bounded(1);
+        let channel = server.pop();
+
+        let _response = channel.try_req(1).unwrap();
+        assert!(channel.try_req(2).is_err());
+
+        let req = server.recv().unwrap();
+        req.reply(3).unwrap();
+    }
+
+    #[test]
+    fn bounded_rendezvous() {
+        let server: Server<u32, u32> = Server::bounded(0);
+        let channel = server.pop();
+
+        thread::spawn(move || {
+            let req = server.recv().unwrap();
+            let (req, value) = req.take();
+            req.reply(value + 2).unwrap();
+        });
+
+        let response = channel.req(1).unwrap();
+        let result = response.recv().unwrap();
+        assert_eq!(result, 3);
+    }
+
+    #[test]
+    fn cancellation() {
+        let server: Server<u32, u32> = Server::new();
+        let channel = server.pop();
+
+        let response = channel.req(1).unwrap();
+        let req = server.recv().unwrap();
+        assert!(!req.is_cancelled());
+
+        drop(response);
+
+        req.cancelled();
+        assert!(req.is_cancelled());
+    }
+
+    #[test]
+    fn select() {
+        let server: Server<u32, u32> = Server::new();
+        let channel = server.pop();
+
+        let a = channel.req(1).unwrap();
+        let b = channel.req(2).unwrap();
+
+        let req = server.recv().unwrap();
+        let (req, value) = req.take();
+        req.reply(value + 1).unwrap();
+
+        let (i, result) = Response::select(&[a, b]);
+        assert_eq!(i, 0);
+        assert_eq!(result.unwrap(), 2);
+    }
+
+    #[test]
+    fn try_iter() {
+        let server: Server<u32, u32> = Server::new();
+        let channel = server.pop();
+
+        let _responses: Vec<_> = (1..=3).map(|i| channel.req(i).unwrap()).collect();
+
+        let values: Vec<u32> = server.try_iter().map(|req| *req.get()).collect();
+        assert_eq!(values, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn iter() {
+        let server: Server<u32, u32> = Server::new();
+        let channel = server.pop();
+
+        thread::spawn(move || {
+            for i in 1..=3 {
+                channel.req(i).unwrap();
+            }
+        });
+
+        let values: Vec<u32> = server.iter().take(3).map(|req| *req.get()).collect();
+        assert_eq!(values, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn into_iter() {
+        let server: Server<u32, u32> = Server::new();
+        let channel = server.pop();
+
+        thread::spawn(move || {
+            for i in 1..=3 {
+                channel.req(i).unwrap();
+            }
+        });
+
+        let values: Vec<u32> = server.into_iter().map(|req| *req.get()).collect();
+        assert_eq!(values, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn shared_server_pool() {
+        let server: Server<u32, u32> = Server::new();
+        let channel = server.pop();
+        let server = server.shared();
+
+        for _ in 0..4 {
+            let server = server.clone();
+            thread::spawn(move || {
+                while let Ok(req) = server.recv() {
+                    let (req, value) = req.take();
+                    req.reply(value + 1).unwrap();
+                }
+            });
+        }
+
+        for i in 0..10 {
+            let response = channel.req(i).unwrap();
+            let reply = response.recv().unwrap();
+            assert_eq!(reply, i + 1);
+        }
+    }
+
     #[test]
     fn recv_timeout() {
         let server: Server<u32, u32> = Server::new();
@@ -344,11 +853,11 @@ mod tests {
 
         let duration = Duration::from_secs(1);
 
-        assert!(server.recv_timeout(duration.clone()).is_err());
+        assert!(server.recv_timeout(duration).is_err());
         let response = channel.req(1).unwrap();
-        assert!(response.recv_timeout(duration.clone()).is_err());
+        assert!(response.recv_timeout(duration).is_err());
 
-        let req = server.recv_timeout(duration.clone()).unwrap();
+        let req = server.recv_timeout(duration).unwrap();
         let (req, value) = req.take();
         req.reply(value + 2).unwrap();
 